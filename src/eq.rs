@@ -0,0 +1,116 @@
+//! Defines [`VersionEq<T>`](struct.VersionEq.html), a newtype enabling version-based
+//! equality and hashing for [`Versioned<T>`](../struct.Versioned.html).
+
+use core::{
+    hash::{
+        Hash,
+        Hasher
+    },
+    ops::Deref
+};
+
+use crate::Versioned;
+
+/// Wraps [`Versioned<T>`](../struct.Versioned.html) so that [`PartialEq`], [`Eq`]
+/// and [`Hash`] compare and hash only the wrapped [`Version`](../struct.Version.html),
+/// never `T` itself.
+///
+/// This is meant for caching calculation results keyed on large values that are
+/// expensive to compare or hash, such as big collections: two `VersionEq<T>`s are
+/// equal iff they share the same identity and counter, which makes `VersionEq<T>`
+/// usable as a `HashMap` key / memoization token without requiring `T: Eq + Hash`.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use versioned::{Versioned, VersionEq};
+///
+/// let mut cache: HashMap<VersionEq<Vec<u8>>, usize> = HashMap::new();
+///
+/// let versioned_value = Versioned::new(vec![1, 2, 3]);
+/// cache.insert(VersionEq::from(versioned_value), 42);
+/// ```
+#[derive(Debug)]
+pub struct VersionEq<T>(Versioned<T>);
+
+impl<T> From<Versioned<T>> for VersionEq<T> {
+    fn from(versioned: Versioned<T>) -> Self {
+        Self(versioned)
+    }
+}
+
+impl<T> Deref for VersionEq<T> {
+    type Target = Versioned<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> PartialEq for VersionEq<T> {
+    /// Compares the wrapped [`Versioned<T>`](../struct.Versioned.html)s' versions,
+    /// ignoring `T` entirely.
+    fn eq(&self, other: &Self) -> bool {
+        self.0.version() == other.0.version()
+    }
+}
+
+impl<T> Eq for VersionEq<T> {
+    // Empty
+}
+
+impl<T> Hash for VersionEq<T> {
+    /// Hashes the wrapped [`Versioned<T>`](../struct.Versioned.html)'s version,
+    /// ignoring `T` entirely.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.version().hash(state);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_when_same_version() {
+        let versioned_value = Versioned::new(vec![1, 2, 3]);
+        let version = versioned_value.version();
+
+        let eq_0 = VersionEq::from(Versioned::with_version(vec![1, 2, 3], version));
+        let eq_1 = VersionEq::from(Versioned::with_version(vec![9, 9, 9], version));
+
+        assert_eq!(eq_0, eq_1, "equal versions make VersionEq equal, regardless of T");
+    }
+
+    #[test]
+    fn not_equal_when_different_identity() {
+        let eq_0 = VersionEq::from(Versioned::new(vec![1, 2, 3]));
+        let eq_1 = VersionEq::from(Versioned::new(vec![1, 2, 3]));
+
+        assert_ne!(eq_0, eq_1, "freshly constructed values have distinct identities");
+    }
+
+    #[test]
+    fn not_equal_after_mutation() {
+        let mut versioned_value = Versioned::new(vec![1, 2, 3]);
+        let before = VersionEq::from(Versioned::with_version(versioned_value.to_vec(), versioned_value.version()));
+
+        versioned_value.push(4);
+
+        let after = VersionEq::from(versioned_value);
+
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn usable_as_hash_map_key() {
+        use std::collections::HashMap;
+
+        let versioned_value = Versioned::new(vec![1, 2, 3]);
+        let key = VersionEq::from(Versioned::with_version(versioned_value.to_vec(), versioned_value.version()));
+
+        let mut cache = HashMap::new();
+        cache.insert(VersionEq::from(versioned_value), "cached");
+
+        assert_eq!(cache.get(&key), Some(&"cached"));
+    }
+}
@@ -10,19 +10,19 @@
 //!
 //! let mut versioned_value = Versioned::new("Hello".to_string());
 //!
-//! assert_eq!(versioned_value.version(), 0, "version is 0 initially");
+//! assert_eq!(versioned_value.version().counter(), 0, "counter is 0 initially");
 //!
 //! // This is an immutable dereference, so it won't change the version.
 //! let value_len = versioned_value.len();
 //!
-//! assert_eq!(versioned_value.version(), 0, "version is unchanged after immutable access");
+//! assert_eq!(versioned_value.version().counter(), 0, "counter is unchanged after immutable access");
 //!
 //! // Now we mutate the value twice.
 //! versioned_value.push_str(" ");
 //! versioned_value.push_str("World!");
 //!
 //! assert_eq!(*versioned_value, "Hello World!");
-//! assert_eq!(versioned_value.version(), 2, "version got incremented once per mutable access");
+//! assert_eq!(versioned_value.version().counter(), 2, "counter got incremented once per mutable access");
 //! ```
 //!
 //! [`Versioned<T>`](struct.Versioned.html) implements [`Deref`](https://doc.rust-lang.org/nightly/core/ops/deref/trait.Deref.html),
@@ -39,43 +39,73 @@
 //! let mut versioned_value = Versioned::new("blabla".to_string());
 //!
 //! look_at(&versioned_value);
-//! assert_eq!(versioned_value.version(), 0);
+//! assert_eq!(versioned_value.version().counter(), 0);
 //!
 //! modify(&mut versioned_value);
-//! assert_eq!(versioned_value.version(), 1, "version increased due to mutable dereference");
+//! assert_eq!(versioned_value.version().counter(), 1, "counter increased due to mutable dereference");
 //! ```
 //! Note from the example above that, since mutations are counted based on mutable dereferences,
-//! version got increased on mutable dereference in the call to `modify()`, even though
+//! the counter got increased on mutable dereference in the call to `modify()`, even though
 //! ultimately no mutation of the value itself took place.
+//!
+//! Unlike a bare counter, [`Version`](struct.Version.html) also carries a process-unique identity,
+//! so versions captured from two unrelated (or cloned) [`Versioned<T>`](struct.Versioned.html)
+//! instances never compare equal by accident:
+//! ```
+//! use versioned::Versioned;
+//!
+//! let versioned_value = Versioned::new("Hello".to_string());
+//! let cloned_value = versioned_value.clone();
+//!
+//! assert_eq!(versioned_value.version().counter(), cloned_value.version().counter());
+//! assert_ne!(versioned_value.version(), cloned_value.version(), "identities differ");
+//! ```
+//!
+//! With its default `std` feature disabled, the crate is `no_std`: none of the types it
+//! defines rely on anything beyond `core`.
+//! `std`-only additions, if any are ever added, are gated behind the `std` feature.
+//!
+//! The optional `uuid` feature mints identities from a random V4 UUID instead of a
+//! process-local counter, which keeps the crate `no_std`, but on targets with no OS
+//! to source randomness from you'll additionally need to register a
+//! [`getrandom`](https://docs.rs/getrandom) backend, since `uuid`'s `v4` generation
+//! depends on it.
 
-use std::{
-    ops::{
-        Deref,
-        DerefMut
-    }
-};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-/// Integer type used for version numbers.
-pub type Version = usize;
+use core::ops::{
+    Deref,
+    DerefMut
+};
 
-/// Initial [`Version`](type.Version.html) for newly constructed [`Versioned<T>`](struct.Versioned.html) instances,
-/// unless a different value was specified via
-/// [`with_version()`](struct.Versioned.html#with_version)
-/// or [`default_with_version()`](struct.Versioned.html#default_with_version) constructors.
-pub const INITIAL_VERSION: Version = 0;
+mod eq;
+mod tracker;
+mod version;
+
+pub use eq::VersionEq;
+pub use tracker::VersionTracker;
+pub use version::{
+    Counter,
+    OverflowPolicy,
+    Version,
+    INITIAL_COUNTER
+};
 
 /// Generic pointer-like wrapper, which counts mutable dereferences.
 ///
+/// Built on top of [`VersionTracker`](struct.VersionTracker.html), which can be used
+/// standalone when precise control over what counts as a change is needed.
+///
 /// See [crate level documentation](index.html) for more info and examples.
 #[derive(Debug)]
-pub struct Versioned<T>(T, Version);
+pub struct Versioned<T>(T, VersionTracker);
 
 impl<T> Default for Versioned<T>
     where T: Default
 {
     /// Constructs new [`Versioned<T>`](struct.Versioned.html) wrapper
     /// containing default value for type `T`
-    /// and version set to [`INITIAL_VERSION`](constant.INITIAL_VERSION.html).
+    /// and a freshly minted [`Version`](struct.Version.html).
     fn default() -> Self {
         Self::new(T::default())
     }
@@ -85,18 +115,13 @@ impl<T> Clone for Versioned<T>
     where T: Clone
 {
     /// Clones [`Versioned<T>`](struct.Versioned.html).
-    /// The clone has its version set to [`INITIAL_VERSION`](constant.INITIAL_VERSION.html).
+    /// The clone gets its own freshly minted [`Version`](struct.Version.html),
+    /// distinct from the original's.
     fn clone(&self) -> Self {
         Self::new(self.0.clone())
     }
 }
 
-impl<T> Copy for Versioned<T>
-    where T: Copy
-{
-    // Empty
-}
-
 impl<T> Deref for Versioned<T> {
     type Target = T;
 
@@ -137,20 +162,40 @@ impl<T> Versioned<T> {
     //
 
     /// Constructs new [`Versioned<T>`](struct.Versioned.html) wrapper
-    /// with version set to [`INITIAL_VERSION`](constant.INITIAL_VERSION.html).
+    /// with a freshly minted [`Version`](struct.Version.html).
     pub fn new(value: T) -> Self {
-        Self::with_version(value, INITIAL_VERSION)
+        Self::with_version(value, Version::new())
     }
 
     /// Constructs new [`Versioned<T>`](struct.Versioned.html) wrapper
     /// with the given version.
     pub fn with_version(value: T, version: Version) -> Self {
-        Self(value, version)
+        Self(value, VersionTracker::with_version(version))
+    }
+
+    /// Constructs new [`Versioned<T>`](struct.Versioned.html) wrapper
+    /// with a freshly minted [`Version`](struct.Version.html) and the given
+    /// [`OverflowPolicy`](enum.OverflowPolicy.html).
+    pub fn with_overflow_policy(value: T, overflow_policy: OverflowPolicy) -> Self {
+        Self(value, VersionTracker::with_policy(overflow_policy))
+    }
+
+    /// Constructs new [`Versioned<T>`](struct.Versioned.html) wrapper
+    /// with the given [`Version`](struct.Version.html) and
+    /// [`OverflowPolicy`](enum.OverflowPolicy.html).
+    pub fn with_version_and_overflow_policy(value: T, version: Version, overflow_policy: OverflowPolicy) -> Self {
+        Self(value, VersionTracker::with_version_and_policy(version, overflow_policy))
     }
 
     /// Returns current version.
     pub fn version(&self) -> Version {
-        self.1
+        self.1.version()
+    }
+
+    /// Returns the [`OverflowPolicy`](enum.OverflowPolicy.html) applied when this
+    /// wrapper's version counter overflows.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.1.overflow_policy()
     }
 
     //
@@ -162,7 +207,7 @@ impl<T> Versioned<T> {
     }
 
     fn as_mut_impl(&mut self) -> &mut T {
-        self.1 += 1;
+        self.1.notify_changed();
 
         &mut self.0
     }
@@ -179,7 +224,7 @@ impl<T> Versioned<T>
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -188,23 +233,23 @@ mod tests {
         let versioned_value = Versioned::new(42);
 
         assert_eq!(*versioned_value, 42);
-        assert_eq!(versioned_value.version(), 0);
+        assert_eq!(versioned_value.version().counter(), INITIAL_COUNTER);
     }
 
     #[test]
     fn version_correct_on_with_version() {
-        let versioned_value = Versioned::with_version("value", 53);
+        let versioned_value = Versioned::with_version("value", Version::with_counter(53));
 
         assert_eq!(*versioned_value, "value");
-        assert_eq!(versioned_value.version(), 53);
+        assert_eq!(versioned_value.version().counter(), 53);
     }
 
     #[test]
     fn version_correct_on_default_with_version() {
-        let versioned_value: Versioned<String> = Versioned::default_with_version(97);
+        let versioned_value: Versioned<String> = Versioned::default_with_version(Version::with_counter(97));
 
         assert_eq!(*versioned_value, String::default());
-        assert_eq!(versioned_value.version(), 97);
+        assert_eq!(versioned_value.version().counter(), 97);
     }
 
     #[test]
@@ -215,12 +260,13 @@ mod tests {
         versioned_value_0.pop();
 
         assert_eq!(*versioned_value_0, "HelloWorld");
-        assert_eq!(versioned_value_0.version(), 2);
+        assert_eq!(versioned_value_0.version().counter(), 2);
 
         let versioned_value_1 = versioned_value_0.clone();
 
         assert_eq!(*versioned_value_1, *versioned_value_0);
-        assert_eq!(versioned_value_1.version(), INITIAL_VERSION);
+        assert_eq!(versioned_value_1.version().counter(), INITIAL_COUNTER);
+        assert_ne!(versioned_value_1.version(), versioned_value_0.version(), "clone gets its own identity");
     }
 
     #[allow(unused_must_use)]
@@ -231,13 +277,13 @@ mod tests {
         let _ = *versioned_value;
         let _ = versioned_value.as_ref();
 
-        assert_eq!(versioned_value.version(), 0);
+        assert_eq!(versioned_value.version().counter(), 0);
 
         *versioned_value;
         versioned_value.as_ref();
 
         assert_eq!(*versioned_value, "some value");
-        assert_eq!(versioned_value.version(), 0);
+        assert_eq!(versioned_value.version().counter(), 0);
     }
 
     #[test]
@@ -247,13 +293,13 @@ mod tests {
         *versioned_value = 10;
 
         assert_eq!(*versioned_value, 10);
-        assert_eq!(versioned_value.version(), 1);
+        assert_eq!(versioned_value.version().counter(), 1);
 
         *versioned_value = 50;
         let _ = versioned_value.as_mut();
 
         assert_eq!(*versioned_value, 50);
-        assert_eq!(versioned_value.version(), 3);
+        assert_eq!(versioned_value.version().counter(), 3);
     }
 
     #[test]
@@ -264,20 +310,33 @@ mod tests {
         let mut versioned_value = Versioned::new("bla".to_string());
 
         look_at(&versioned_value);
-        assert_eq!(versioned_value.version(), 0);
+        assert_eq!(versioned_value.version().counter(), 0);
 
         modify(&mut versioned_value);
-        assert_eq!(versioned_value.version(), 1);
+        assert_eq!(versioned_value.version().counter(), 1);
+    }
+
+    #[test]
+    fn version_wraps_with_new_identity_on_overflow_by_default() {
+        let mut versioned_value: Versioned<String> =
+            Versioned::default_with_version(Version::with_counter(Counter::MAX));
+        let identity_before = versioned_value.version().id();
+
+        versioned_value.push_str("!");
+
+        assert_eq!(versioned_value.version().counter(), 0);
+        assert_ne!(versioned_value.version().id(), identity_before);
     }
 
     #[test]
     #[should_panic(expected = "overflow")]
-    fn panic_on_version_overflow() {
-        let mut versioned_value: Versioned<String> = Versioned::default_with_version(Version::max_value() - 2);
+    fn panic_on_version_overflow_with_panic_policy() {
+        let mut versioned_value = Versioned::with_version_and_overflow_policy(
+            "value".to_string(),
+            Version::with_counter(Counter::MAX),
+            OverflowPolicy::Panic
+        );
 
-        versioned_value.push_str("This");
-        versioned_value.push_str("Will");
         versioned_value.push_str("Overflow");
-        versioned_value.push_str("Version");
     }
 }
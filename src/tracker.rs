@@ -0,0 +1,163 @@
+//! Defines [`VersionTracker`](struct.VersionTracker.html), a standalone change counter
+//! decoupled from any wrapped value.
+
+use crate::version::{
+    OverflowPolicy,
+    Version
+};
+
+/// Standalone [`Version`](struct.Version.html) tracker, without a wrapped value.
+///
+/// [`Versioned<T>`](../struct.Versioned.html) bumps its version on every mutable
+/// dereference, even when the call turns out not to mutate anything (see the
+/// [crate level documentation](../index.html) for an example). For a struct with
+/// many fields, or for callers who want precise control over what counts as a
+/// change, embed a `VersionTracker` as a plain field instead and call
+/// [`notify_changed()`](struct.VersionTracker.html#method.notify_changed) only on
+/// actual mutations.
+#[derive(Debug)]
+pub struct VersionTracker(Version, OverflowPolicy);
+
+impl VersionTracker {
+    /// Constructs a new [`VersionTracker`](struct.VersionTracker.html)
+    /// with a freshly minted [`Version`](struct.Version.html)
+    /// and the default [`OverflowPolicy`](enum.OverflowPolicy.html).
+    pub fn new() -> Self {
+        Self::with_version(Version::new())
+    }
+
+    /// Constructs a new [`VersionTracker`](struct.VersionTracker.html)
+    /// with the given [`Version`](struct.Version.html)
+    /// and the default [`OverflowPolicy`](enum.OverflowPolicy.html).
+    pub fn with_version(version: Version) -> Self {
+        Self::with_version_and_policy(version, OverflowPolicy::default())
+    }
+
+    /// Constructs a new [`VersionTracker`](struct.VersionTracker.html)
+    /// with a freshly minted [`Version`](struct.Version.html)
+    /// and the given [`OverflowPolicy`](enum.OverflowPolicy.html).
+    pub fn with_policy(overflow_policy: OverflowPolicy) -> Self {
+        Self::with_version_and_policy(Version::new(), overflow_policy)
+    }
+
+    /// Constructs a new [`VersionTracker`](struct.VersionTracker.html)
+    /// with the given [`Version`](struct.Version.html) and
+    /// [`OverflowPolicy`](enum.OverflowPolicy.html).
+    pub fn with_version_and_policy(version: Version, overflow_policy: OverflowPolicy) -> Self {
+        Self(version, overflow_policy)
+    }
+
+    /// Returns current version.
+    pub fn version(&self) -> Version {
+        self.0
+    }
+
+    /// Returns the [`OverflowPolicy`](enum.OverflowPolicy.html) applied when the
+    /// tracked version's counter overflows.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.1
+    }
+
+    /// Records a change, incrementing the tracked version's counter.
+    ///
+    /// If the counter would overflow, applies this tracker's
+    /// [`OverflowPolicy`](enum.OverflowPolicy.html) instead of incrementing.
+    pub fn notify_changed(&mut self) {
+        if self.0.checked_increment() {
+            return;
+        }
+
+        match self.1 {
+            OverflowPolicy::Panic => panic!("Version counter overflow"),
+            OverflowPolicy::Wrap => self.0 = Version::new(),
+            OverflowPolicy::Saturate => {
+                // Counter is already at its maximum and identity is left unchanged.
+            }
+        }
+    }
+}
+
+impl Default for VersionTracker {
+    /// Equivalent to [`VersionTracker::new()`](struct.VersionTracker.html#method.new).
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for VersionTracker {
+    /// Clones [`VersionTracker`](struct.VersionTracker.html).
+    /// The clone gets its own freshly minted [`Version`](struct.Version.html),
+    /// distinct from the original's, consistent with
+    /// [`Versioned<T>`](../struct.Versioned.html)'s clone semantics. The
+    /// [`OverflowPolicy`](enum.OverflowPolicy.html) is carried over unchanged.
+    fn clone(&self) -> Self {
+        Self::with_policy(self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::version::Counter;
+
+    #[test]
+    fn version_zero_on_new() {
+        let tracker = VersionTracker::new();
+
+        assert_eq!(tracker.version().counter(), 0);
+    }
+
+    #[test]
+    fn notify_changed_increments_counter() {
+        let mut tracker = VersionTracker::new();
+
+        tracker.notify_changed();
+        tracker.notify_changed();
+
+        assert_eq!(tracker.version().counter(), 2);
+    }
+
+    #[test]
+    fn clone_gets_fresh_identity_and_same_policy() {
+        let mut tracker_0 = VersionTracker::with_policy(OverflowPolicy::Saturate);
+        tracker_0.notify_changed();
+
+        let tracker_1 = tracker_0.clone();
+
+        assert_eq!(tracker_1.version().counter(), 0);
+        assert_ne!(tracker_1.version(), tracker_0.version());
+        assert_eq!(tracker_1.overflow_policy(), OverflowPolicy::Saturate);
+    }
+
+    #[test]
+    fn default_policy_wraps_and_mints_new_identity_on_overflow() {
+        let mut tracker = VersionTracker::with_version(Version::with_counter(Counter::MAX));
+        let identity_before = tracker.version().id();
+
+        tracker.notify_changed();
+
+        assert_eq!(tracker.version().counter(), 0);
+        assert_ne!(tracker.version().id(), identity_before);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn panic_policy_panics_on_overflow() {
+        let mut tracker =
+            VersionTracker::with_version_and_policy(Version::with_counter(Counter::MAX), OverflowPolicy::Panic);
+
+        tracker.notify_changed();
+    }
+
+    #[test]
+    fn saturate_policy_keeps_identity_and_max_counter_on_overflow() {
+        let mut tracker =
+            VersionTracker::with_version_and_policy(Version::with_counter(Counter::MAX), OverflowPolicy::Saturate);
+        let identity_before = tracker.version().id();
+
+        tracker.notify_changed();
+
+        assert_eq!(tracker.version().counter(), Counter::MAX);
+        assert_eq!(tracker.version().id(), identity_before);
+    }
+}
@@ -0,0 +1,181 @@
+//! Defines [`Version`](struct.Version.html), the value used to identify and order
+//! the mutation history tracked by a [`Versioned<T>`](../struct.Versioned.html).
+
+#[cfg(not(feature = "uuid"))]
+use core::sync::atomic::{
+    AtomicU64,
+    Ordering
+};
+
+/// Integer type used for the mutation counter component of a [`Version`](struct.Version.html).
+pub type Counter = usize;
+
+/// Initial counter value for a freshly constructed [`Version`](struct.Version.html),
+/// unless a different value was specified via
+/// [`Version::with_counter()`](struct.Version.html#method.with_counter).
+pub const INITIAL_COUNTER: Counter = 0;
+
+#[cfg(not(feature = "uuid"))]
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Generates a fresh, process-unique identity for a [`Version`](struct.Version.html).
+///
+/// Backed by a random V4 UUID when the `uuid` feature is enabled, and by a
+/// monotonically increasing counter otherwise. On targets with no OS to source
+/// randomness from (e.g. bare-metal `no_std`), enabling `uuid` additionally
+/// requires registering a [`getrandom`](https://docs.rs/getrandom) backend for
+/// the target, since `uuid`'s `v4` generation depends on it.
+#[cfg(feature = "uuid")]
+fn generate_id() -> u128 {
+    uuid::Uuid::new_v4().as_u128()
+}
+
+#[cfg(not(feature = "uuid"))]
+fn generate_id() -> u128 {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed) as u128
+}
+
+/// Identifies one mutation history of a value wrapped in
+/// [`Versioned<T>`](../struct.Versioned.html).
+///
+/// A `Version` pairs a process-unique `id`, minted whenever a new history begins
+/// (construction or [`Versioned::clone()`](../struct.Versioned.html#method.clone)),
+/// with a `counter` that is bumped on every mutable access. Two `Version`s are
+/// compared by `id` first and `counter` second, so a `Version` captured from one
+/// history never aliases one captured from another, even if their counters happen
+/// to match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    id: u128,
+    counter: Counter,
+}
+
+impl Version {
+    /// Constructs a new [`Version`](struct.Version.html) with a fresh identity
+    /// and counter set to [`INITIAL_COUNTER`](constant.INITIAL_COUNTER.html).
+    pub fn new() -> Self {
+        Self::with_counter(INITIAL_COUNTER)
+    }
+
+    /// Constructs a new [`Version`](struct.Version.html) with a fresh identity
+    /// and the given counter.
+    pub fn with_counter(counter: Counter) -> Self {
+        Self {
+            id: generate_id(),
+            counter
+        }
+    }
+
+    /// Returns the process-unique identity of this [`Version`](struct.Version.html)'s history.
+    pub fn id(&self) -> u128 {
+        self.id
+    }
+
+    /// Returns the current mutation counter.
+    pub fn counter(&self) -> Counter {
+        self.counter
+    }
+
+    /// Attempts to increment the mutation counter, keeping the identity unchanged.
+    /// Returns `false` without modifying `self` if the counter is already at
+    /// [`Counter::MAX`](https://doc.rust-lang.org/std/primitive.usize.html#associatedconstant.MAX).
+    pub(crate) fn checked_increment(&mut self) -> bool {
+        match self.counter.checked_add(1) {
+            Some(counter) => {
+                self.counter = counter;
+                true
+            },
+            None => false
+        }
+    }
+}
+
+impl Default for Version {
+    /// Equivalent to [`Version::new()`](struct.Version.html#method.new).
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Controls what happens when a [`Version`](struct.Version.html)'s counter would
+/// overflow on increment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Panics. This was the crate's only behavior before [`OverflowPolicy`](enum.OverflowPolicy.html)
+    /// was introduced; kept available for callers who want a hard failure instead of
+    /// silently restarting the history.
+    Panic,
+    /// Wraps the counter back to [`INITIAL_COUNTER`](constant.INITIAL_COUNTER.html)
+    /// and mints a fresh identity, so a stale captured [`Version`](struct.Version.html)
+    /// reliably compares as changed instead of risking an alias with a future counter
+    /// value. The default.
+    Wrap,
+    /// Leaves the counter at [`Counter::MAX`](https://doc.rust-lang.org/std/primitive.usize.html#associatedconstant.MAX)
+    /// and keeps the identity unchanged, so further mutations stop being distinguishable
+    /// from one another but no panic or identity change occurs.
+    Saturate
+}
+
+impl Default for OverflowPolicy {
+    /// [`OverflowPolicy::Wrap`](enum.OverflowPolicy.html#variant.Wrap), the safe
+    /// choice for long-lived values used as cache keys.
+    fn default() -> Self {
+        OverflowPolicy::Wrap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_has_initial_counter() {
+        let version = Version::new();
+
+        assert_eq!(version.counter(), INITIAL_COUNTER);
+    }
+
+    #[test]
+    fn with_counter_sets_counter() {
+        let version = Version::with_counter(53);
+
+        assert_eq!(version.counter(), 53);
+    }
+
+    #[test]
+    fn distinct_versions_have_distinct_ids() {
+        let version_0 = Version::new();
+        let version_1 = Version::new();
+
+        assert_ne!(version_0.id(), version_1.id());
+        assert_ne!(version_0, version_1);
+    }
+
+    #[test]
+    fn same_id_compares_by_counter() {
+        let mut version = Version::new();
+        let earlier = version;
+
+        assert!(version.checked_increment());
+
+        assert_eq!(version.id(), earlier.id());
+        assert!(version > earlier);
+        assert_ne!(version, earlier);
+    }
+
+    #[test]
+    fn checked_increment_fails_at_max_counter() {
+        let mut version = Version::with_counter(Counter::MAX);
+
+        assert!(!version.checked_increment());
+        assert_eq!(version.counter(), Counter::MAX, "counter is left untouched on failure");
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn generate_id_is_backed_by_a_v4_uuid() {
+        let id = generate_id();
+
+        assert_eq!(uuid::Uuid::from_u128(id).get_version_num(), 4, "id is minted from a v4 UUID");
+    }
+}